@@ -0,0 +1,186 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Arithmetic building blocks for `GF(2^m)` fields too large to hold a
+//! full log/exp lookup table (`m` up to 127).
+//!
+//! As with [clmul](crate::clmul), the `g2gen` proc-macro crate that would
+//! pick between a table-based and a computation-based backend for
+//! [g2p](crate::g2p) is not part of this source tree, so these are free
+//! functions rather than a second `GaloisField` codegen path: a future
+//! big-field mode would bake `modulus` and `degree` in as associated
+//! constants on its generated type and delegate its trait impl to these.
+//!
+//! Elements are represented as `u128`, which covers every degree up to
+//! 127. A degree-`m` element's carry-less product can have degree up to
+//! `2m - 2`, which for `m` beyond 64 no longer fits in a single `u128`,
+//! so the product is carried as a double-width `(low, high)` pair of
+//! `u128`s until it has been reduced back down to `degree` bits.
+
+/// Degree of a `GF(2)[x]` polynomial, i.e. the index of its highest set bit.
+fn poly_degree(p: u128) -> i32 {
+    127 - p.leading_zeros() as i32
+}
+
+/// Degree of a double-width `(low, high)` `GF(2)[x]` polynomial value, or
+/// `-1` for the zero polynomial.
+fn wide_degree(lo: u128, hi: u128) -> i32 {
+    if hi != 0 {
+        128 + poly_degree(hi)
+    } else if lo != 0 {
+        poly_degree(lo)
+    } else {
+        -1
+    }
+}
+
+/// XORs `modulus << shift` into the double-width value `(lo, hi)`.
+fn xor_shifted(lo: &mut u128, hi: &mut u128, modulus: u128, shift: u32) {
+    if shift == 0 {
+        *lo ^= modulus;
+    } else if shift < 128 {
+        *lo ^= modulus << shift;
+        *hi ^= modulus >> (128 - shift);
+    } else {
+        *hi ^= modulus << (shift - 128);
+    }
+}
+
+/// Carry-less multiplies `a` and `b`, each holding at most `degree` bits,
+/// producing the full double-width product as `(low, high)` halves.
+fn clmul_wide(a: u128, b: u128, degree: u32) -> (u128, u128) {
+    let mut lo: u128 = 0;
+    let mut hi: u128 = 0;
+
+    for bit in 0..degree {
+        if (b >> bit) & 1 == 1 {
+            if bit == 0 {
+                lo ^= a;
+            } else {
+                lo ^= a << bit;
+                hi ^= a >> (128 - bit);
+            }
+        }
+    }
+
+    (lo, hi)
+}
+
+/// Reduces a double-width product modulo `modulus`, a degree-`degree`
+/// irreducible polynomial with its degree-`degree` term set explicitly.
+fn reduce_wide(mut lo: u128, mut hi: u128, modulus: u128, degree: u32) -> u128 {
+    loop {
+        let deg = wide_degree(lo, hi);
+        if deg < degree as i32 {
+            break;
+        }
+
+        xor_shifted(&mut lo, &mut hi, modulus, (deg as u32) - degree);
+    }
+
+    lo
+}
+
+/// Multiplies two elements of `GF(2^m)`, each represented as a `u128`
+/// smaller than `2^degree`, reducing the carry-less product modulo
+/// `modulus` (a degree-`degree` polynomial with its degree-`degree` term
+/// set explicitly, e.g. `0x1_0000_0000_0000_001b` for `GF(2^64)` with polynomial
+/// `x^64 + x^4 + x^3 + x + 1`).
+pub fn mul(a: u128, b: u128, modulus: u128, degree: u32) -> u128 {
+    assert!(degree <= 127, "degree must fit in a u128");
+
+    let (lo, hi) = clmul_wide(a, b, degree);
+    reduce_wide(lo, hi, modulus, degree)
+}
+
+/// Inverts `a` modulo the irreducible polynomial `modulus` using the
+/// Extended Euclidean Algorithm over `GF(2)[x]`: runs the polynomial GCD
+/// of `a` and `modulus`, tracking the Bezout coefficient of `a`, which
+/// equals `a^-1` once the remainder reaches `1`.
+///
+/// Panics if `a` is zero.
+pub fn inverse(a: u128, modulus: u128) -> u128 {
+    assert_ne!(a, 0, "zero has no inverse");
+
+    let (mut r0, mut r1) = (modulus, a);
+    let (mut t0, mut t1): (u128, u128) = (0, 1);
+
+    while r1 != 1 {
+        let shift = poly_degree(r0) - poly_degree(r1);
+        assert!(shift >= 0, "a is not invertible modulo modulus");
+
+        r0 ^= r1 << shift;
+        t0 ^= t1 << shift;
+
+        if r0 == 0 {
+            panic!("a is not invertible modulo modulus");
+        }
+
+        if poly_degree(r0) < poly_degree(r1) {
+            core::mem::swap(&mut r0, &mut r1);
+            core::mem::swap(&mut t0, &mut t1);
+        }
+    }
+
+    t1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // AES's GF(2^8), polynomial x^8 + x^4 + x^3 + x + 1 (0x11b). 0x57 *
+    // 0x83 == 0xc1 is the textbook worked example for this field.
+    const GF256_MODULUS: u128 = 0x11b;
+
+    #[test]
+    fn mul_matches_known_gf256_vector() {
+        assert_eq!(mul(0x57, 0x83, GF256_MODULUS, 8), 0xc1);
+    }
+
+    #[test]
+    fn mul_by_zero_and_one() {
+        assert_eq!(mul(0x57, 0, GF256_MODULUS, 8), 0);
+        assert_eq!(mul(0x57, 1, GF256_MODULUS, 8), 0x57);
+    }
+
+    #[test]
+    fn inverse_round_trips_gf256() {
+        for a in 1..=255u128 {
+            assert_eq!(mul(a, inverse(a, GF256_MODULUS), GF256_MODULUS, 8), 1);
+        }
+    }
+
+    // x^89 + x^38 + 1, a known irreducible (and primitive) trinomial,
+    // exercising the double-width path for a degree beyond 64.
+    const GF89_DEGREE: u32 = 89;
+    const GF89_MODULUS: u128 = (1 << GF89_DEGREE) | (1 << 38) | 1;
+
+    #[test]
+    fn mul_round_trips_degree_89() {
+        for &a in &[3u128, 5, 123_456_789, 0x1fff_ffff_ffff_ffff_ffff] {
+            let inv = inverse(a, GF89_MODULUS);
+            assert_eq!(mul(a, inv, GF89_MODULUS, GF89_DEGREE), 1);
+        }
+    }
+
+    // x^127 + x + 1: the request's upper bound, and too wide a product
+    // to fit in a single u128 (degree up to 2 * 127 - 2 = 252).
+    const GF127_DEGREE: u32 = 127;
+    const GF127_MODULUS: u128 = (1 << GF127_DEGREE) | 0b11;
+
+    #[test]
+    fn mul_round_trips_degree_127() {
+        let a: u128 = 0x1234_5678_9abc_def0_1122_3344_5566_7788 & ((1 << GF127_DEGREE) - 1);
+        let b: u128 = 0xfedc_ba98_7654_3210_1111_2222_3333_4444 & ((1 << GF127_DEGREE) - 1);
+
+        let product = mul(a, b, GF127_MODULUS, GF127_DEGREE);
+        assert!(product < (1 << GF127_DEGREE));
+
+        let inv = inverse(a, GF127_MODULUS);
+        assert_eq!(mul(a, inv, GF127_MODULUS, GF127_DEGREE), 1);
+    }
+}
@@ -0,0 +1,156 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Hardware carry-less multiplication primitives.
+//!
+//! [g2p](crate::g2p) itself still generates multiplication via log/exp
+//! lookup tables: the codegen lives in the `g2gen` proc-macro crate, which
+//! is not part of this source tree, so it cannot be switched to emit calls
+//! into this module yet. This module provides the building block that
+//! codegen would dispatch to: a carry-less multiply of two field elements
+//! followed by reduction modulo an irreducible polynomial, with a portable
+//! bit-serial fallback for targets without a carry-less multiply
+//! instruction.
+//!
+//! Dispatch happens at runtime, not compile time, so the fast path is
+//! taken on capable hardware without requiring special `RUSTFLAGS`:
+//!
+//! * x86_64 uses `_mm_clmulepi64_si128` (`pclmulqdq`) when
+//!   `is_x86_feature_detected!("pclmulqdq")`.
+//! * aarch64 uses `vmull_p64` (`pmull`) when
+//!   `is_aarch64_feature_detected!("aes")`.
+//! * Everything else, or a CPU without the feature, falls back to
+//!   [clmul_bitserial].
+
+/// Carry-less multiplies `a` and `b`, returning the full double-width
+/// product, using the best instruction available on the *running* CPU
+/// (detected once and cached, not just at compile time) and falling back
+/// to [clmul_bitserial] otherwise.
+pub fn clmul(a: u64, b: u64) -> u128 {
+    #[cfg(target_arch = "x86_64")]
+    {
+        use std::sync::OnceLock;
+
+        static HAS_PCLMULQDQ: OnceLock<bool> = OnceLock::new();
+        if *HAS_PCLMULQDQ.get_or_init(|| is_x86_feature_detected!("pclmulqdq")) {
+            return unsafe { clmul_x86(a, b) };
+        }
+    }
+
+    #[cfg(target_arch = "aarch64")]
+    {
+        use std::sync::OnceLock;
+
+        static HAS_PMULL: OnceLock<bool> = OnceLock::new();
+        if *HAS_PMULL.get_or_init(|| is_aarch64_feature_detected!("aes")) {
+            return unsafe { clmul_aarch64(a, b) };
+        }
+    }
+
+    clmul_bitserial(a, b)
+}
+
+/// Portable bit-serial carry-less multiply, used as a fallback when no
+/// hardware instruction is available on the running CPU.
+pub fn clmul_bitserial(a: u64, b: u64) -> u128 {
+    let mut product: u128 = 0;
+
+    for i in 0..64 {
+        if (b >> i) & 1 == 1 {
+            product ^= (a as u128) << i;
+        }
+    }
+
+    product
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "pclmulqdq")]
+unsafe fn clmul_x86(a: u64, b: u64) -> u128 {
+    use core::arch::x86_64::{__m128i, _mm_clmulepi64_si128, _mm_set_epi64x, _mm_storeu_si128};
+
+    let a = _mm_set_epi64x(0, a as i64);
+    let b = _mm_set_epi64x(0, b as i64);
+    let product: __m128i = _mm_clmulepi64_si128::<0x00>(a, b);
+
+    let mut out = [0u8; 16];
+    _mm_storeu_si128(out.as_mut_ptr().cast(), product);
+    u128::from_le_bytes(out)
+}
+
+#[cfg(target_arch = "aarch64")]
+#[target_feature(enable = "aes")]
+unsafe fn clmul_aarch64(a: u64, b: u64) -> u128 {
+    use core::arch::aarch64::vmull_p64;
+
+    vmull_p64(a, b)
+}
+
+/// Reduces a double-width carry-less product modulo the field's
+/// irreducible polynomial `modulus`, with its degree-`degree` term set
+/// explicitly (e.g. `0x11b` for the AES/`GF(2^8)` polynomial).
+///
+/// `degree` is the degree of the field (e.g. `8` for `GF(2^8)`). The
+/// reduction folds the product down one bit at a time from the top; a
+/// hardware backend would instead fold whole words at once by
+/// carry-less-multiplying the high half by a precomputed reduction
+/// constant, XOR-ing the result in, and repeating once more.
+pub fn reduce(product: u128, modulus: u64, degree: u32) -> u64 {
+    let mut result = product;
+    let modulus = modulus as u128;
+
+    let mut bit = 127;
+    while bit >= degree {
+        if (result >> bit) & 1 == 1 {
+            result ^= modulus << (bit - degree);
+        }
+
+        if bit == 0 {
+            break;
+        }
+        bit -= 1;
+    }
+
+    result as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clmul_matches_bitserial_fallback() {
+        let pairs = [
+            (0x0u64, 0x0u64),
+            (0x1, 0x1),
+            (0xffff_ffff_ffff_ffff, 0x1),
+            (0x1234_5678_9abc_def0, 0xfedc_ba98_7654_3210),
+            (0x8000_0000_0000_0000, 0x8000_0000_0000_0000),
+        ];
+
+        for (a, b) in pairs {
+            assert_eq!(clmul(a, b), clmul_bitserial(a, b));
+        }
+    }
+
+    #[test]
+    fn clmul_is_commutative() {
+        assert_eq!(clmul(0x57, 0x83), clmul(0x83, 0x57));
+    }
+
+    // AES's GF(2^8), polynomial x^8 + x^4 + x^3 + x + 1 (0x11b). 0x57 *
+    // 0x83 == 0xc1 is the textbook worked example for this field.
+    #[test]
+    fn reduce_matches_known_gf256_vector() {
+        let product = clmul(0x57, 0x83);
+        assert_eq!(reduce(product, 0x11b, 8), 0xc1);
+    }
+
+    #[test]
+    fn reduce_is_a_no_op_below_degree() {
+        assert_eq!(reduce(0x57, 0x11b, 8), 0x57);
+    }
+}
@@ -6,6 +6,17 @@
 
 use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
 
+pub mod big_field;
+pub mod clmul;
+pub mod poly;
+pub mod prime_field;
+pub mod reed_solomon;
+pub mod secret_sharing;
+pub mod slice_ops;
+
+#[cfg(test)]
+mod test_field;
+
 /// Procedural macro to generate binary galois fields
 pub use g2gen::g2p;
 
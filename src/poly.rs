@@ -0,0 +1,222 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Irreducible and primitive polynomial utilities over `GF(2)[x]`.
+//!
+//! These are plain functions over `u128` bit-packed
+//! polynomials, so they can be called both from ordinary code and from
+//! `g2gen`'s macro-expansion-time logic to validate a user-supplied
+//! polynomial or pick one automatically - `g2gen` itself is not part of
+//! this source tree, so that wiring (making `g2p!(GF256, 8)` auto-select
+//! a polynomial) is not done here, but the validation these functions
+//! would drive is fully usable on its own.
+//!
+//! A polynomial of degree `m` is represented as a `u128` with its
+//! degree-`m` term set explicitly, e.g. `0x11b` for `x^8 + x^4 + x^3 + x
+//! + 1`.
+
+/// Degree of a `GF(2)[x]` polynomial, i.e. the index of its highest set bit.
+fn degree(p: u128) -> u32 {
+    127 - p.leading_zeros()
+}
+
+/// Polynomial remainder of `a / b` over `GF(2)[x]`.
+fn rem(mut a: u128, b: u128) -> u128 {
+    if a == 0 {
+        return 0;
+    }
+
+    let db = degree(b);
+    while a != 0 && degree(a) >= db {
+        a ^= b << (degree(a) - db);
+    }
+
+    a
+}
+
+/// Multiplies `a` and `b` modulo `modulus`, a degree-`m` polynomial.
+fn mulmod(a: u128, b: u128, modulus: u128) -> u128 {
+    let mut product: u128 = 0;
+    for bit in 0..degree(modulus) {
+        if (b >> bit) & 1 == 1 {
+            product ^= a << bit;
+        }
+    }
+
+    rem(product, modulus)
+}
+
+/// Computes `base^exp mod modulus` by square-and-multiply.
+fn powmod(base: u128, mut exp: u128, modulus: u128) -> u128 {
+    let mut result: u128 = 1;
+    let mut base = rem(base, modulus);
+
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mulmod(result, base, modulus);
+        }
+        base = mulmod(base, base, modulus);
+        exp >>= 1;
+    }
+
+    result
+}
+
+/// Greatest common divisor of two `GF(2)[x]` polynomials.
+fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        let r = rem(a, b);
+        a = b;
+        b = r;
+    }
+
+    a
+}
+
+/// Distinct prime factors of `n`, found by trial division.
+fn prime_factors(mut n: u128) -> Vec<u128> {
+    let mut factors = Vec::new();
+
+    let mut d = 2u128;
+    while d * d <= n {
+        if n.is_multiple_of(d) {
+            factors.push(d);
+            while n.is_multiple_of(d) {
+                n /= d;
+            }
+        }
+        d += 1;
+    }
+
+    if n > 1 {
+        factors.push(n);
+    }
+
+    factors
+}
+
+/// Tests whether `p`, a degree-`m` polynomial over `GF(2)`, is
+/// irreducible, via the Rabin irreducibility test: `x^(2^m) == x mod p`,
+/// and `gcd(x^(2^(m/q)) - x, p) == 1` for every prime factor `q` of `m`.
+pub fn is_irreducible(p: u128, m: u32) -> bool {
+    if m == 0 {
+        return false;
+    }
+
+    let pow2m = powmod(2, 1u128 << m, p);
+    if pow2m != rem(2, p) {
+        return false;
+    }
+
+    for q in prime_factors(m as u128) {
+        let exp = 1u128 << (m as u128 / q);
+        let term = powmod(2, exp, p) ^ 2;
+        if term == 0 {
+            return false;
+        }
+
+        if gcd(term, p) != 1 {
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Tests whether `p`, an irreducible degree-`m` polynomial over `GF(2)`,
+/// is primitive, i.e. `x` generates the whole multiplicative group of
+/// `GF(2^m)`: the order of `x` modulo `p` must be `2^m - 1`, which holds
+/// iff `x^((2^m - 1) / q) != 1 mod p` for every prime factor `q` of `2^m -
+/// 1`.
+pub fn is_primitive(p: u128, m: u32) -> bool {
+    if !is_irreducible(p, m) {
+        return false;
+    }
+
+    let order = (1u128 << m) - 1;
+    prime_factors(order)
+        .into_iter()
+        .all(|q| powmod(2, order / q, p) != 1)
+}
+
+/// Searches for the lowest-weight primitive polynomial of degree `m`.
+///
+/// This is a brute-force search over all `2^m` candidate polynomials of
+/// degree `m` and is only practical for modest `m` (a handful of bits);
+/// larger degrees should use a precomputed polynomial instead.
+pub fn primitive_poly(m: u32) -> Option<u128> {
+    let leading = 1u128 << m;
+
+    let mut best: Option<(u32, u128)> = None;
+    for low_bits in 0..leading {
+        let candidate = leading | low_bits;
+        if !is_primitive(candidate, m) {
+            continue;
+        }
+
+        let weight = candidate.count_ones();
+        if best.is_none_or(|(best_weight, _)| weight < best_weight) {
+            best = Some((weight, candidate));
+        }
+    }
+
+    best.map(|(_, p)| p)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recognizes_known_irreducible_and_primitive_polynomials() {
+        // x^4 + x + 1, the modulus test_field::Gf16 uses: irreducible and primitive.
+        assert!(is_irreducible(0b1_0011, 4));
+        assert!(is_primitive(0b1_0011, 4));
+
+        // AES's GF(256) modulus, x^8 + x^4 + x^3 + x + 1: irreducible, but the
+        // textbook example of an irreducible polynomial that is *not*
+        // primitive (its root has multiplicative order 51, not 255).
+        assert!(is_irreducible(0x11b, 8));
+        assert!(!is_primitive(0x11b, 8));
+
+        // x^8 + x^4 + x^3 + x^2 + 1, another degree-8 modulus: both
+        // irreducible and primitive.
+        assert!(is_irreducible(0x11d, 8));
+        assert!(is_primitive(0x11d, 8));
+    }
+
+    #[test]
+    fn rejects_reducible_polynomials() {
+        // x^4 + 1 == (x + 1)^4 over GF(2): reducible, so neither test should
+        // accept it.
+        assert!(!is_irreducible(0b1_0001, 4));
+        assert!(!is_primitive(0b1_0001, 4));
+    }
+
+    #[test]
+    fn rejects_irreducible_but_non_primitive_polynomials() {
+        // x^4 + x^3 + x^2 + x + 1, the 5th cyclotomic polynomial: irreducible
+        // (2 has order 4 mod 5), but its root only has order 5, not 15.
+        assert!(is_irreducible(0b1_1111, 4));
+        assert!(!is_primitive(0b1_1111, 4));
+    }
+
+    #[test]
+    fn primitive_poly_finds_a_polynomial_that_is_actually_primitive() {
+        for m in 2..=8 {
+            let p = primitive_poly(m).expect("a primitive polynomial should exist");
+            assert!(is_primitive(p, m), "primitive_poly({m}) = {p:#x} is not primitive");
+        }
+    }
+
+    #[test]
+    fn primitive_poly_picks_the_lowest_weight_candidate() {
+        // x^4 + x + 1 has weight 3, the lowest possible for a primitive
+        // degree-4 polynomial (weight 2 would mean just x^4 + 1, which is
+        // reducible).
+        assert_eq!(primitive_poly(4), Some(0b1_0011));
+    }
+}
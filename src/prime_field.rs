@@ -0,0 +1,189 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A prime field `GF(p)`, implementing [GaloisField] by hand.
+//!
+//! This is a partial stand-in, not a complete implementation of what was
+//! asked for. `g2p!` itself only generates binary fields `GF(2^m)`;
+//! `g2p!(GF7, 7)` and `g2p!(GF9, 3, 2)` do not work and are not made to
+//! work here - that requires macro-level codegen in the `g2gen`
+//! proc-macro crate, which is not part of this source tree, and no such
+//! wiring exists in this module. What [Gfp] actually provides is a
+//! macro-independent `GF(p)` type a caller can use directly: a
+//! const-generic type over the prime `P` and a generator `G` of its
+//! multiplicative group, reducing with ordinary integer arithmetic modulo
+//! `P`.
+//!
+//! The `GF(p^m)` extension-field case - degree-`<m` polynomials with
+//! `GF(p)` coefficients reduced modulo an irreducible degree-`m`
+//! polynomial - is simply missing from this module. There is no type, no
+//! partial implementation, and no follow-up tracking it here.
+
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+
+use crate::GaloisField;
+
+/// An element of the prime field `GF(P)`, with `G` a generator of its
+/// multiplicative group.
+///
+/// `P` must be prime; this is not checked by the type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Gfp<const P: u64, const G: u64>(u64);
+
+impl<const P: u64, const G: u64> Gfp<P, G> {
+    /// Builds a field element from an integer, reducing it modulo `P`.
+    pub fn new(value: u64) -> Self {
+        Self(value % P)
+    }
+
+    /// The element's value, in `0..P`.
+    pub fn value(self) -> u64 {
+        self.0
+    }
+
+    /// `base^exp mod P`, by square-and-multiply.
+    fn pow_mod(base: u64, mut exp: u64) -> u64 {
+        let mut result = 1u128;
+        let mut base = (base as u128) % (P as u128);
+
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = (result * base) % (P as u128);
+            }
+            base = (base * base) % (P as u128);
+            exp >>= 1;
+        }
+
+        result as u64
+    }
+}
+
+impl<const P: u64, const G: u64> From<u64> for Gfp<P, G> {
+    fn from(value: u64) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<const P: u64, const G: u64> Add for Gfp<P, G> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self((self.0 + rhs.0) % P)
+    }
+}
+
+impl<const P: u64, const G: u64> AddAssign for Gfp<P, G> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const P: u64, const G: u64> Sub for Gfp<P, G> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self((self.0 + P - rhs.0) % P)
+    }
+}
+
+impl<const P: u64, const G: u64> SubAssign for Gfp<P, G> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const P: u64, const G: u64> Mul for Gfp<P, G> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self(((self.0 as u128 * rhs.0 as u128) % P as u128) as u64)
+    }
+}
+
+impl<const P: u64, const G: u64> MulAssign for Gfp<P, G> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<const P: u64, const G: u64> Div for Gfp<P, G> {
+    type Output = Self;
+
+    /// Divides by `rhs`, computing its inverse as `rhs^(P - 2) mod P` by
+    /// Fermat's little theorem.
+    fn div(self, rhs: Self) -> Self {
+        assert_ne!(rhs.0, 0, "division by zero");
+
+        Self((self.0 as u128 * Self::pow_mod(rhs.0, P - 2) as u128 % P as u128) as u64)
+    }
+}
+
+impl<const P: u64, const G: u64> DivAssign for Gfp<P, G> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl<const P: u64, const G: u64> GaloisField for Gfp<P, G> {
+    const ZERO: Self = Gfp(0);
+    const ONE: Self = Gfp(1 % P);
+    const GENERATOR: Self = Gfp(G % P);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Gf7 = Gfp<7, 3>;
+
+    #[test]
+    fn add_sub_round_trip() {
+        for a in 0..7 {
+            for b in 0..7 {
+                let (a, b) = (Gf7::new(a), Gf7::new(b));
+                assert_eq!((a + b) - b, a);
+            }
+        }
+    }
+
+    #[test]
+    fn mul_div_round_trip() {
+        for a in 0..7 {
+            for b in 1..7 {
+                let (a, b) = (Gf7::new(a), Gf7::new(b));
+                assert_eq!((a * b) / b, a);
+            }
+        }
+    }
+
+    #[test]
+    fn inverse_via_division() {
+        for a in 1..7 {
+            let a = Gf7::new(a);
+            assert_eq!(Gf7::ONE / a * a, Gf7::ONE);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "division by zero")]
+    fn division_by_zero_panics() {
+        let _ = Gf7::ONE / Gf7::ZERO;
+    }
+
+    #[test]
+    fn generator_generates_the_multiplicative_group() {
+        let g = Gf7::GENERATOR;
+
+        let mut seen = std::collections::HashSet::new();
+        let mut val = Gf7::ONE;
+        for _ in 0..6 {
+            val *= g;
+            seen.insert(val.value());
+        }
+
+        assert_eq!(seen, (1..7).collect());
+    }
+}
@@ -0,0 +1,372 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Systematic Reed-Solomon encoding and decoding over any [GaloisField].
+//!
+//! Both [RsEncoder] and [RsDecoder] are generic over any field generated by
+//! [g2p](crate::g2p), so they work unmodified with `GF256` or any other
+//! field the macro produces.
+//!
+//! The code is assumed to be full-length, i.e. `n` is the order of the
+//! multiplicative group generated by `GENERATOR` (`n = 2^m - 1` for a
+//! `g2p!`-generated binary field of size `2^m`).
+
+use core::fmt;
+
+use crate::GaloisField;
+
+/// Errors that can occur while decoding a Reed-Solomon codeword.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// The received word could not be corrected: either too many symbols
+    /// were in error, or the errors were not consistent with a valid
+    /// codeword.
+    UncorrectableErrors,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::UncorrectableErrors => write!(f, "too many errors to correct"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Multiplies two polynomials, highest-degree coefficient first.
+fn poly_mul<F: GaloisField>(a: &[F], b: &[F]) -> Vec<F> {
+    let mut out = vec![F::ZERO; a.len() + b.len() - 1];
+    for (i, &ai) in a.iter().enumerate() {
+        if ai == F::ZERO {
+            continue;
+        }
+
+        for (j, &bj) in b.iter().enumerate() {
+            out[i + j] += ai * bj;
+        }
+    }
+    out
+}
+
+/// Computes `dividend mod divisor`, both highest-degree coefficient first.
+fn poly_rem<F: GaloisField>(dividend: &[F], divisor: &[F]) -> Vec<F> {
+    let mut rem = dividend.to_vec();
+
+    for i in 0..=(rem.len() - divisor.len()) {
+        let coef = rem[i];
+        if coef == F::ZERO {
+            continue;
+        }
+
+        for (j, &dj) in divisor.iter().enumerate() {
+            rem[i + j] -= coef * dj;
+        }
+    }
+
+    rem.split_off(rem.len() - (divisor.len() - 1))
+}
+
+/// Evaluates a polynomial (highest-degree coefficient first) at `x`.
+fn poly_eval<F: GaloisField>(coeffs: &[F], x: F) -> F {
+    coeffs.iter().fold(F::ZERO, |acc, &c| acc * x + c)
+}
+
+/// A systematic Reed-Solomon encoder.
+///
+/// Encodes a `k`-symbol message into an `n`-symbol codeword by appending
+/// `n - k` parity symbols, able to correct up to `(n - k) / 2` erroneous
+/// symbols.
+pub struct RsEncoder<F: GaloisField> {
+    n: usize,
+    k: usize,
+    generator: Vec<F>,
+}
+
+impl<F: GaloisField> RsEncoder<F> {
+    /// Builds the generator polynomial `g(x) = \prod_{i=0}^{n-k-1} (x - GENERATOR^i)`
+    /// for an `(n, k)` code.
+    pub fn new(n: usize, k: usize) -> Self {
+        assert!(k >= 1 && k < n, "k must be between 1 and n - 1");
+
+        let mut generator = vec![F::ONE];
+        for i in 0..(n - k) {
+            let root = F::GENERATOR.pow(i);
+            generator = poly_mul(&generator, &[F::ONE, F::ZERO - root]);
+        }
+
+        Self { n, k, generator }
+    }
+
+    /// The codeword length.
+    pub fn n(&self) -> usize {
+        self.n
+    }
+
+    /// The message length.
+    pub fn k(&self) -> usize {
+        self.k
+    }
+
+    /// Encodes a `k`-symbol message into an `n`-symbol systematic codeword.
+    pub fn encode(&self, message: &[F]) -> Vec<F> {
+        assert_eq!(message.len(), self.k, "message must have k symbols");
+
+        let mut shifted = message.to_vec();
+        shifted.extend(std::iter::repeat_n(F::ZERO, self.n - self.k));
+
+        let parity = poly_rem(&shifted, &self.generator);
+
+        let mut codeword = message.to_vec();
+        codeword.extend(parity.into_iter().map(|p| F::ZERO - p));
+        codeword
+    }
+}
+
+/// A Reed-Solomon decoder able to correct up to `(n - k) / 2` errors.
+pub struct RsDecoder<F: GaloisField> {
+    n: usize,
+    k: usize,
+    _marker: core::marker::PhantomData<F>,
+}
+
+impl<F: GaloisField> RsDecoder<F> {
+    /// Creates a decoder for an `(n, k)` code.
+    pub fn new(n: usize, k: usize) -> Self {
+        assert!(k >= 1 && k < n, "k must be between 1 and n - 1");
+
+        Self {
+            n,
+            k,
+            _marker: core::marker::PhantomData,
+        }
+    }
+
+    /// Computes the `n - k` syndromes `S_j = r(GENERATOR^j)` of a received word.
+    fn syndromes(&self, received: &[F]) -> Vec<F> {
+        (0..(self.n - self.k))
+            .map(|j| poly_eval(received, F::GENERATOR.pow(j)))
+            .collect()
+    }
+
+    /// Runs the Berlekamp-Massey algorithm on the syndromes to find the
+    /// error-locator polynomial `\Lambda(x)`, lowest-degree coefficient first.
+    fn berlekamp_massey(&self, syndromes: &[F]) -> Vec<F> {
+        let mut c = vec![F::ONE];
+        let mut b = vec![F::ONE];
+        let mut l = 0usize;
+        let mut m = 1usize;
+        let mut bcoeff = F::ONE;
+
+        for n in 0..syndromes.len() {
+            let mut delta = syndromes[n];
+            for i in 1..=l {
+                delta += c[i] * syndromes[n - i];
+            }
+
+            if delta == F::ZERO {
+                m += 1;
+            } else if 2 * l <= n {
+                let t = c.clone();
+
+                let scale = delta / bcoeff;
+                c.resize(c.len().max(b.len() + m), F::ZERO);
+                for (i, &bi) in b.iter().enumerate() {
+                    c[i + m] -= scale * bi;
+                }
+
+                l = n + 1 - l;
+                b = t;
+                bcoeff = delta;
+                m = 1;
+            } else {
+                let scale = delta / bcoeff;
+                if c.len() < b.len() + m {
+                    c.resize(b.len() + m, F::ZERO);
+                }
+                for (i, &bi) in b.iter().enumerate() {
+                    c[i + m] -= scale * bi;
+                }
+
+                m += 1;
+            }
+        }
+
+        c.truncate(l + 1);
+        c
+    }
+
+    /// Corrects a received `n`-symbol word in place, returning the number
+    /// of corrected symbols.
+    pub fn decode(&self, received: &mut [F]) -> Result<usize, Error> {
+        assert_eq!(received.len(), self.n, "received word must have n symbols");
+
+        let syndromes = self.syndromes(received);
+        if syndromes.iter().all(|&s| s == F::ZERO) {
+            return Ok(0);
+        }
+
+        let lambda = self.berlekamp_massey(&syndromes);
+        let num_errors = lambda.len() - 1;
+        if num_errors > (self.n - self.k) / 2 {
+            return Err(Error::UncorrectableErrors);
+        }
+
+        // Chien search: Lambda's roots are the inverses of the error
+        // locations X_l = GENERATOR^(n-1-idx), where idx is the array
+        // index (the codeword is stored highest-degree coefficient
+        // first, so array index idx holds the coefficient of x^(n-1-idx)).
+        // Testing x = GENERATOR^i for i in 0..n and finding Lambda(x) == 0
+        // means x == X_l^-1 directly, i.e. i == -(n-1-idx) mod n, so
+        // idx == (i + n - 1) % n.
+        let mut error_locations = Vec::with_capacity(num_errors);
+        for i in 0..self.n {
+            let x = F::GENERATOR.pow(i);
+            let value = lambda
+                .iter()
+                .enumerate()
+                .fold(F::ZERO, |acc, (j, &lj)| acc + lj * x.pow(j));
+
+            if value == F::ZERO {
+                let idx = (i + self.n - 1) % self.n;
+                error_locations.push((idx, x));
+            }
+        }
+
+        if error_locations.len() != num_errors {
+            return Err(Error::UncorrectableErrors);
+        }
+
+        // Error evaluator polynomial Omega(x) = S(x) * Lambda(x) mod x^(n-k),
+        // both lowest-degree coefficient first.
+        let s_lo: Vec<F> = syndromes.clone();
+        let mut omega = vec![F::ZERO; s_lo.len() + lambda.len() - 1];
+        for (i, &si) in s_lo.iter().enumerate() {
+            for (j, &lj) in lambda.iter().enumerate() {
+                omega[i + j] += si * lj;
+            }
+        }
+        omega.truncate(self.n - self.k);
+
+        // Formal derivative of Lambda: the coefficient of x^(i-1) is the
+        // i-fold sum of the coefficient of x^i.
+        let lambda_deriv: Vec<F> = lambda
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(i, &li)| {
+                let mut sum = F::ZERO;
+                for _ in 0..i {
+                    sum += li;
+                }
+                sum
+            })
+            .collect();
+
+        for &(pos, x_inv) in &error_locations {
+            let omega_val = omega
+                .iter()
+                .enumerate()
+                .fold(F::ZERO, |acc, (j, &oj)| acc + oj * x_inv.pow(j));
+            let lambda_deriv_val = lambda_deriv
+                .iter()
+                .enumerate()
+                .fold(F::ZERO, |acc, (j, &lj)| acc + lj * x_inv.pow(j));
+
+            if lambda_deriv_val == F::ZERO {
+                return Err(Error::UncorrectableErrors);
+            }
+
+            // The syndromes are S_j = r(GENERATOR^j) for j starting at 0
+            // rather than at 1, so the usual Forney formula needs an
+            // extra factor of X_l to compensate.
+            let x_loc = F::GENERATOR.pow((self.n - 1 - pos) % self.n);
+            let magnitude = F::ZERO - x_loc * omega_val / lambda_deriv_val;
+            received[pos] -= magnitude;
+        }
+
+        Ok(error_locations.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_field::Gf16;
+
+    use super::*;
+
+    fn message() -> Vec<Gf16> {
+        (0..11).map(|i| Gf16::from((i % 15) as u8 + 1)).collect()
+    }
+
+    #[test]
+    fn round_trip_no_errors() {
+        let encoder = RsEncoder::<Gf16>::new(15, 11);
+        let decoder = RsDecoder::<Gf16>::new(15, 11);
+
+        let codeword = encoder.encode(&message());
+        let mut received = codeword.clone();
+
+        assert_eq!(decoder.decode(&mut received), Ok(0));
+        assert_eq!(received, codeword);
+    }
+
+    #[test]
+    fn round_trip_single_error() {
+        let encoder = RsEncoder::<Gf16>::new(15, 11);
+        let decoder = RsDecoder::<Gf16>::new(15, 11);
+
+        let codeword = encoder.encode(&message());
+
+        let mut received = codeword.clone();
+        received[5] += Gf16::from(1u8);
+
+        assert_eq!(decoder.decode(&mut received), Ok(1));
+        assert_eq!(received, codeword);
+    }
+
+    #[test]
+    fn round_trip_two_errors() {
+        let encoder = RsEncoder::<Gf16>::new(15, 11);
+        let decoder = RsDecoder::<Gf16>::new(15, 11);
+
+        let codeword = encoder.encode(&message());
+
+        let mut received = codeword.clone();
+        received[2] += Gf16::from(3u8);
+        received[9] += Gf16::from(5u8);
+
+        assert_eq!(decoder.decode(&mut received), Ok(2));
+        assert_eq!(received, codeword);
+    }
+
+    #[test]
+    fn too_many_errors_is_rejected() {
+        let encoder = RsEncoder::<Gf16>::new(15, 11);
+        let decoder = RsDecoder::<Gf16>::new(15, 11);
+
+        let codeword = encoder.encode(&message());
+
+        let mut received = codeword.clone();
+        received[0] += Gf16::from(1u8);
+        received[1] += Gf16::from(1u8);
+        received[2] += Gf16::from(1u8);
+
+        assert_eq!(decoder.decode(&mut received), Err(Error::UncorrectableErrors));
+    }
+
+    #[test]
+    #[should_panic(expected = "k must be between 1 and n - 1")]
+    fn encoder_rejects_k_zero() {
+        RsEncoder::<Gf16>::new(15, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "k must be between 1 and n - 1")]
+    fn decoder_rejects_k_zero() {
+        RsDecoder::<Gf16>::new(15, 0);
+    }
+}
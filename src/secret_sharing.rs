@@ -0,0 +1,184 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Shamir's `(k, n)` threshold secret sharing over any [GaloisField].
+//!
+//! Each byte of the secret is shared independently as one element of
+//! `GF256` (or any other `g2p!`-generated field whose elements can hold a
+//! byte), so the scheme is most useful instantiated with `GF256`.
+//!
+//! This is the only module in the tree that needs an external crate
+//! (`rand`, for the random polynomial coefficients in [split]); as with
+//! `g2gen` (see [clmul](crate::clmul)), no commit in this series touches
+//! `Cargo.toml`, so there is nowhere to pin a version. The API used here
+//! is `rand` 0.9's (`rand::rng()`, `Rng::random()`) - whoever wires up the
+//! manifest should pin at least that.
+
+use rand::Rng;
+
+use crate::GaloisField;
+
+/// One share of a shared byte: the evaluation point `x` and the
+/// corresponding polynomial value `y = p(x)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Share<F: GaloisField> {
+    x: F,
+    y: F,
+}
+
+/// Splits a single field element `secret` into `n` shares, any `k` of
+/// which are sufficient to recombine it.
+///
+/// Picks a random degree-`(k - 1)` polynomial `p(x) = secret + a_1 x + ... +
+/// a_{k-1} x^{k-1}` and returns the shares `(i, p(i))` for `i = 1..=n`.
+fn split_element<F, R>(rng: &mut R, secret: F, k: usize, n: usize) -> Vec<Share<F>>
+where
+    F: GaloisField + From<u8>,
+    R: Rng + ?Sized,
+{
+    assert!(k >= 1 && k <= n, "k must be between 1 and n");
+
+    let mut coeffs = vec![secret];
+    for _ in 1..k {
+        coeffs.push(F::from(rng.random::<u8>()));
+    }
+
+    (1..=n)
+        .map(|i| {
+            let x = F::from(i as u8);
+            let y = coeffs
+                .iter()
+                .rev()
+                .fold(F::ZERO, |acc, &c| acc * x + c);
+
+            Share { x, y }
+        })
+        .collect()
+}
+
+/// Recombines `k` or more shares of a single field element via Lagrange
+/// interpolation at `x = 0`.
+fn recombine_element<F: GaloisField>(shares: &[Share<F>]) -> F {
+    let mut secret = F::ZERO;
+
+    for (j, share_j) in shares.iter().enumerate() {
+        let mut term = share_j.y;
+
+        for (m, share_m) in shares.iter().enumerate() {
+            if m == j {
+                continue;
+            }
+
+            term = term * share_m.x / (share_m.x - share_j.x);
+        }
+
+        secret += term;
+    }
+
+    secret
+}
+
+/// Splits a byte-oriented `secret` into `n` shares, any `k` of which are
+/// sufficient to recombine it.
+///
+/// Each byte of `secret` is shared independently as one element of `F`,
+/// typically `GF256`.
+///
+/// ```rust
+/// use g2p::{g2p, secret_sharing};
+///
+/// g2p!(GF256, 8);
+///
+/// let secret = b"top secret";
+/// let shares = secret_sharing::split::<GF256, _>(&mut rand::rng(), secret, 3, 5);
+///
+/// let recombined = secret_sharing::recombine(&shares[0..3]);
+/// assert_eq!(recombined, secret);
+/// ```
+pub fn split<F, R>(rng: &mut R, secret: &[u8], k: usize, n: usize) -> Vec<Vec<Share<F>>>
+where
+    F: GaloisField + From<u8>,
+    R: Rng + ?Sized,
+{
+    secret
+        .iter()
+        .map(|&byte| split_element(rng, F::from(byte), k, n))
+        .collect::<Vec<_>>()
+        .into_iter()
+        .fold(vec![Vec::new(); n], |mut shares_per_holder, byte_shares| {
+            for (holder, share) in shares_per_holder.iter_mut().zip(byte_shares) {
+                holder.push(share);
+            }
+            shares_per_holder
+        })
+}
+
+/// Recombines a byte-oriented secret from at least `k` of the shares
+/// produced by [split].
+///
+/// `shares` holds one list of per-byte [Share]s per contributing holder.
+pub fn recombine<F: GaloisField + Into<u8>>(shares: &[Vec<Share<F>>]) -> Vec<u8> {
+    let len = shares.first().map_or(0, Vec::len);
+
+    (0..len)
+        .map(|i| {
+            let byte_shares: Vec<Share<F>> = shares.iter().map(|holder| holder[i]).collect();
+            recombine_element(&byte_shares).into()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::test_field::Gf256;
+
+    use super::*;
+
+    fn rng() -> StdRng {
+        StdRng::seed_from_u64(0x5ecc_7e57)
+    }
+
+    #[test]
+    fn k_of_n_threshold_recombines() {
+        let secret = b"threshold secret";
+        let shares = split::<Gf256, _>(&mut rng(), secret, 3, 5);
+
+        for subset in [[0, 1, 2], [0, 2, 4], [1, 3, 4]] {
+            let chosen: Vec<_> = subset.iter().map(|&i| shares[i].clone()).collect();
+            assert_eq!(recombine(&chosen), secret);
+        }
+    }
+
+    #[test]
+    fn fewer_than_k_shares_do_not_recombine_the_secret() {
+        let secret = b"threshold secret";
+        let shares = split::<Gf256, _>(&mut rng(), secret, 3, 5);
+
+        let chosen = &shares[0..2];
+        assert_ne!(recombine(chosen), secret);
+    }
+
+    #[test]
+    fn k_equals_one_every_share_alone_is_the_secret() {
+        let secret = b"a";
+        let shares = split::<Gf256, _>(&mut rng(), secret, 1, 4);
+
+        for holder in &shares {
+            assert_eq!(recombine(&[holder.clone()]), secret);
+        }
+    }
+
+    #[test]
+    fn k_equals_n_needs_every_share() {
+        let secret = b"needs-all";
+        let shares = split::<Gf256, _>(&mut rng(), secret, 4, 4);
+
+        assert_eq!(recombine(&shares), secret);
+        assert_ne!(recombine(&shares[0..3]), secret);
+    }
+}
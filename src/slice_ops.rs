@@ -0,0 +1,231 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Batched arithmetic over slices of field elements.
+//!
+//! [GaloisFieldSlice] is implemented for every [GaloisField] with a
+//! straightforward element-wise loop. For the common `GF(2^8)` case,
+//! [mul_scalar_gf256] additionally provides a nibble-table/`pshufb`-based
+//! implementation that processes 16 elements per instruction, dispatched
+//! at runtime via `is_x86_feature_detected!("ssse3")` (cached after the
+//! first call) so the fast path is taken on capable hardware without
+//! requiring special `RUSTFLAGS`, falling back to the element-wise
+//! nibble-table lookup everywhere else.
+
+use crate::GaloisField;
+
+/// Batched operations over slices of a [GaloisField].
+///
+/// Blanket-implemented for every `GaloisField` via a plain per-element
+/// loop; see [mul_scalar_gf256] for a vectorized alternative for
+/// byte-sized fields.
+pub trait GaloisFieldSlice: GaloisField {
+    /// `dst[i] = a[i] + b[i]` for every `i`.
+    fn add_slice(dst: &mut [Self], a: &[Self], b: &[Self]) {
+        assert_eq!(dst.len(), a.len());
+        assert_eq!(dst.len(), b.len());
+
+        for i in 0..dst.len() {
+            dst[i] = a[i] + b[i];
+        }
+    }
+
+    /// `dst[i] = src[i] * k` for every `i`.
+    fn mul_scalar(dst: &mut [Self], src: &[Self], k: Self) {
+        assert_eq!(dst.len(), src.len());
+
+        for i in 0..dst.len() {
+            dst[i] = src[i] * k;
+        }
+    }
+
+    /// The dot product `sum(a[i] * b[i])`.
+    fn dot(a: &[Self], b: &[Self]) -> Self {
+        assert_eq!(a.len(), b.len());
+
+        a.iter()
+            .zip(b.iter())
+            .fold(Self::ZERO, |acc, (&x, &y)| acc + x * y)
+    }
+}
+
+impl<F: GaloisField> GaloisFieldSlice for F {}
+
+/// Builds the two 16-entry nibble multiplication tables for `k`: `lo[i] =
+/// F::from(i) * k` and `hi[i] = F::from(i << 4) * k`, so that `x * k ==
+/// lo[x & 0xf] + hi[x >> 4]`.
+fn nibble_tables<F>(k: F) -> ([F; 16], [F; 16])
+where
+    F: GaloisField + From<u8> + Into<u8>,
+{
+    let mut lo = [F::ZERO; 16];
+    let mut hi = [F::ZERO; 16];
+
+    for i in 0..16u8 {
+        lo[i as usize] = F::from(i) * k;
+        hi[i as usize] = F::from(i << 4) * k;
+    }
+
+    (lo, hi)
+}
+
+/// Computes `dst[i] = src[i] * k` for a byte-sized field, using a
+/// nibble-table/`pshufb` vectorized implementation on targets that
+/// support SSSE3, and an element-wise nibble-table lookup everywhere
+/// else.
+pub fn mul_scalar_gf256<F>(dst: &mut [F], src: &[F], k: F)
+where
+    F: GaloisField + From<u8> + Into<u8>,
+{
+    assert_eq!(dst.len(), src.len());
+
+    let (lo, hi) = nibble_tables(k);
+
+    #[cfg(target_arch = "x86_64")]
+    {
+        use std::sync::OnceLock;
+
+        static HAS_SSSE3: OnceLock<bool> = OnceLock::new();
+        if *HAS_SSSE3.get_or_init(|| is_x86_feature_detected!("ssse3")) {
+            let lo_bytes: [u8; 16] = core::array::from_fn(|i| lo[i].into());
+            let hi_bytes: [u8; 16] = core::array::from_fn(|i| hi[i].into());
+
+            let mut chunks = src.chunks_exact(16);
+            let mut out = dst.chunks_exact_mut(16);
+
+            for (chunk, out_chunk) in (&mut chunks).zip(&mut out) {
+                let bytes: [u8; 16] = core::array::from_fn(|i| chunk[i].into());
+                let result = unsafe { mul_chunk_ssse3(bytes, lo_bytes, hi_bytes) };
+                for (d, r) in out_chunk.iter_mut().zip(result.iter()) {
+                    *d = F::from(*r);
+                }
+            }
+
+            let remainder_start = src.len() - chunks.remainder().len();
+            for i in remainder_start..src.len() {
+                let byte: u8 = src[i].into();
+                dst[i] = lo[(byte & 0xf) as usize] + hi[(byte >> 4) as usize];
+            }
+
+            return;
+        }
+    }
+
+    for i in 0..src.len() {
+        let byte: u8 = src[i].into();
+        dst[i] = lo[(byte & 0xf) as usize] + hi[(byte >> 4) as usize];
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "ssse3")]
+unsafe fn mul_chunk_ssse3(bytes: [u8; 16], lo: [u8; 16], hi: [u8; 16]) -> [u8; 16] {
+    use core::arch::x86_64::{_mm_and_si128, _mm_loadu_si128, _mm_set1_epi8, _mm_shuffle_epi8, _mm_srli_epi16, _mm_storeu_si128, _mm_xor_si128};
+
+    let input = _mm_loadu_si128(bytes.as_ptr().cast());
+    let lo_table = _mm_loadu_si128(lo.as_ptr().cast());
+    let hi_table = _mm_loadu_si128(hi.as_ptr().cast());
+
+    let low_nibble_mask = _mm_set1_epi8(0x0f);
+    let low_nibble = _mm_and_si128(input, low_nibble_mask);
+    let high_nibble = _mm_and_si128(_mm_srli_epi16::<4>(input), low_nibble_mask);
+
+    let low_result = _mm_shuffle_epi8(lo_table, low_nibble);
+    let high_result = _mm_shuffle_epi8(hi_table, high_nibble);
+    let result = _mm_xor_si128(low_result, high_result);
+
+    let mut out = [0u8; 16];
+    _mm_storeu_si128(out.as_mut_ptr().cast(), result);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_field::Gf256;
+
+    use super::*;
+
+    #[test]
+    fn mul_scalar_gf256_matches_elementwise_reference() {
+        let k = Gf256::from(0x57);
+        let src: Vec<Gf256> = (0..=255u8).map(Gf256::from).collect();
+        let mut dst = vec![Gf256::ZERO; src.len()];
+
+        mul_scalar_gf256(&mut dst, &src, k);
+
+        for (s, d) in src.iter().zip(dst.iter()) {
+            assert_eq!(*d, *s * k);
+        }
+    }
+
+    #[test]
+    fn mul_scalar_gf256_handles_non_multiple_of_16_lengths() {
+        let k = Gf256::from(0x13);
+        let src: Vec<Gf256> = (0..=20u8).map(Gf256::from).collect();
+        let mut dst = vec![Gf256::ZERO; src.len()];
+
+        mul_scalar_gf256(&mut dst, &src, k);
+
+        for (s, d) in src.iter().zip(dst.iter()) {
+            assert_eq!(*d, *s * k);
+        }
+    }
+
+    #[test]
+    fn add_slice_matches_elementwise_addition() {
+        let a: Vec<Gf256> = (0..=20u8).map(Gf256::from).collect();
+        let b: Vec<Gf256> = (0..=20u8).rev().map(Gf256::from).collect();
+        let mut dst = vec![Gf256::ZERO; a.len()];
+
+        Gf256::add_slice(&mut dst, &a, &b);
+
+        for ((x, y), d) in a.iter().zip(b.iter()).zip(dst.iter()) {
+            assert_eq!(*d, *x + *y);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn add_slice_rejects_mismatched_lengths() {
+        let a = [Gf256::ONE; 3];
+        let b = [Gf256::ONE; 2];
+        let mut dst = [Gf256::ZERO; 3];
+
+        Gf256::add_slice(&mut dst, &a, &b);
+    }
+
+    #[test]
+    fn mul_scalar_blanket_default_matches_elementwise_multiplication() {
+        let k = Gf256::from(0x9a);
+        let src: Vec<Gf256> = (0..=20u8).map(Gf256::from).collect();
+        let mut dst = vec![Gf256::ZERO; src.len()];
+
+        Gf256::mul_scalar(&mut dst, &src, k);
+
+        for (s, d) in src.iter().zip(dst.iter()) {
+            assert_eq!(*d, *s * k);
+        }
+    }
+
+    #[test]
+    fn dot_matches_manual_sum_of_products() {
+        let a: Vec<Gf256> = (0..=20u8).map(Gf256::from).collect();
+        let b: Vec<Gf256> = (0..=20u8).rev().map(Gf256::from).collect();
+
+        let expected = a
+            .iter()
+            .zip(b.iter())
+            .fold(Gf256::ZERO, |acc, (&x, &y)| acc + x * y);
+
+        assert_eq!(Gf256::dot(&a, &b), expected);
+    }
+
+    #[test]
+    fn dot_of_empty_slices_is_zero() {
+        let empty: [Gf256; 0] = [];
+        assert_eq!(Gf256::dot(&empty, &empty), Gf256::ZERO);
+    }
+}
@@ -0,0 +1,134 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A hand-rolled `GF(2^m)`, shared by the test suites of modules that
+//! need a concrete [GaloisField] to exercise generic code without
+//! depending on `g2p!` (which lives in the `g2gen` proc-macro crate, not
+//! part of this source tree).
+
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+
+use crate::GaloisField;
+
+/// An element of `GF(2^m)`, reduced modulo `MODULUS` (given with its
+/// leading term set, e.g. `0x11b` for `GF(2^8)`), with `GENERATOR` a
+/// generator of its multiplicative group. `m` is derived from
+/// `MODULUS`'s bit length.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct TestField<const MODULUS: u16, const GEN: u8>(u8);
+
+impl<const MODULUS: u16, const GEN: u8> TestField<MODULUS, GEN> {
+    const DEGREE: u32 = 15 - MODULUS.leading_zeros();
+
+    fn mul_raw(a: u8, b: u8) -> u8 {
+        let mut result: u16 = 0;
+        for bit in 0..Self::DEGREE {
+            if (b >> bit) & 1 == 1 {
+                result ^= (a as u16) << bit;
+            }
+        }
+
+        for shift in (Self::DEGREE..2 * Self::DEGREE).rev() {
+            if (result >> shift) & 1 == 1 {
+                result ^= MODULUS << (shift - Self::DEGREE);
+            }
+        }
+
+        result as u8
+    }
+
+    fn inverse_raw(a: u8) -> u8 {
+        assert_ne!(a, 0, "zero has no inverse");
+        (1..(1u16 << Self::DEGREE))
+            .map(|candidate| candidate as u8)
+            .find(|&candidate| Self::mul_raw(a, candidate) == 1)
+            .unwrap()
+    }
+}
+
+impl<const MODULUS: u16, const GEN: u8> Add for TestField<MODULUS, GEN> {
+    type Output = Self;
+
+    // Addition in GF(2^m) is XOR, not the usual carrying addition.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn add(self, rhs: Self) -> Self {
+        Self(self.0 ^ rhs.0)
+    }
+}
+
+impl<const MODULUS: u16, const GEN: u8> AddAssign for TestField<MODULUS, GEN> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const MODULUS: u16, const GEN: u8> Sub for TestField<MODULUS, GEN> {
+    type Output = Self;
+
+    // Subtraction is the same as addition in GF(2^m): XOR is its own inverse.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn sub(self, rhs: Self) -> Self {
+        self + rhs
+    }
+}
+
+impl<const MODULUS: u16, const GEN: u8> SubAssign for TestField<MODULUS, GEN> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const MODULUS: u16, const GEN: u8> Mul for TestField<MODULUS, GEN> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self(Self::mul_raw(self.0, rhs.0))
+    }
+}
+
+impl<const MODULUS: u16, const GEN: u8> MulAssign for TestField<MODULUS, GEN> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<const MODULUS: u16, const GEN: u8> Div for TestField<MODULUS, GEN> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        Self(Self::mul_raw(self.0, Self::inverse_raw(rhs.0)))
+    }
+}
+
+impl<const MODULUS: u16, const GEN: u8> DivAssign for TestField<MODULUS, GEN> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl<const MODULUS: u16, const GEN: u8> GaloisField for TestField<MODULUS, GEN> {
+    const ZERO: Self = Self(0);
+    const ONE: Self = Self(1);
+    const GENERATOR: Self = Self(GEN);
+}
+
+impl<const MODULUS: u16, const GEN: u8> From<u8> for TestField<MODULUS, GEN> {
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+
+impl<const MODULUS: u16, const GEN: u8> From<TestField<MODULUS, GEN>> for u8 {
+    fn from(value: TestField<MODULUS, GEN>) -> Self {
+        value.0
+    }
+}
+
+/// `GF(16)`, modulus `x^4 + x + 1` (`0b1_0011`).
+pub(crate) type Gf16 = TestField<0b1_0011, 2>;
+
+/// `GF(256)`, AES's modulus `x^8 + x^4 + x^3 + x + 1` (`0x11b`).
+pub(crate) type Gf256 = TestField<0x11b, 3>;